@@ -332,7 +332,7 @@ pub fn last_local_dir() -> PathBuf {
 
 fn save_last_local_dir(path: &Path) -> anyhow::Result<()> {
     let mut cfg = cori_run::config::Config::load()?;
-    cfg.set("launcher.last_local_dir", &path.to_string_lossy())?;
+    cfg.set_literal("launcher.last_local_dir", &path.to_string_lossy())?;
     cfg.save()?;
     Ok(())
 }
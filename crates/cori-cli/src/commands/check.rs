@@ -5,6 +5,7 @@ use cori_broker::capabilities::{self, Capabilities, Capability, CapabilityKind,
 use cori_broker::identity::{IdentitySource, OsUser};
 use cori_broker::runtime as broker_runtime;
 use cori_protocol::{CompiledWorkflow, Placement, StepKind, WorkerIdentity, task_queue_for};
+use serde_json::{Value as JsonValue, json};
 
 use cori_run::remote;
 use cori_run::{paths, planner, runtime as cli_runtime, temporal_endpoint, workflow_loader};
@@ -51,9 +52,13 @@ pub struct CapabilityReadiness {
     pub remedy: Option<String>,
 }
 
-pub fn check(path: String, update: bool, assume_yes: bool) -> Result<()> {
+pub fn check(path: String, update: bool, assume_yes: bool, json_out: bool) -> Result<()> {
     let report = preflight(&path, update, assume_yes)?;
-    print_report(&report);
+    if json_out {
+        println!("{}", serde_json::to_string_pretty(&report_json(&report))?);
+    } else {
+        print_report(&report);
+    }
     if !report.ready {
         std::process::exit(2);
     }
@@ -144,6 +149,31 @@ pub fn preflight(arg: &str, update: bool, assume_yes: bool) -> Result<PreflightR
     })
 }
 
+/// Machine-readable form of a [`PreflightReport`]. Shared by
+/// `cori check --json` and the MCP `check` tool so the two never drift.
+pub(crate) fn report_json(report: &PreflightReport) -> JsonValue {
+    json!({
+        "ready": report.ready,
+        "endpoint": report.endpoint,
+        "temporal_reachable": report.temporal_reachable,
+        "user_task_queue": report.user_task_queue,
+        "steps": report.steps.iter().map(|s| json!({
+            "step_name": s.step_name,
+            "kind": kind_label(s.kind),
+            "task_queue": s.task_queue,
+            "missing": s.missing,
+        })).collect::<Vec<_>>(),
+        "capabilities": report.capabilities.iter().map(|c| json!({
+            "id": c.id,
+            "kind": format!("{:?}", c.kind),
+            "authed": c.authed,
+            "detail": c.detail,
+            "remedy": c.remedy,
+        })).collect::<Vec<_>>(),
+        "warnings": report.warnings,
+    })
+}
+
 /// Advisory lints on the compiled workflow shape.
 fn build_warnings(compiled: &CompiledWorkflow) -> Vec<String> {
     let mut out = Vec::new();
@@ -1,8 +1,10 @@
-//! `cori config get|set`.
+//! `cori config get|set|validate`.
 
 use anyhow::Result;
+use serde_json::json;
 
-use cori_run::config::Config;
+use cori_run::config::{Config, ConfigIssue};
+use cori_run::paths;
 
 pub fn get(key: Option<&str>) -> Result<()> {
     let cfg = Config::load()?;
@@ -12,7 +14,12 @@ pub fn get(key: Option<&str>) -> Result<()> {
                 println!("{}", render_value(v));
             }
             None => {
-                eprintln!("no config key `{k}`");
+                match cfg.unresolved(k) {
+                    Some(issue) => {
+                        eprintln!("config key `{k}` is set but unusable: {}", issue.reason)
+                    }
+                    None => eprintln!("no config key `{k}`"),
+                }
                 std::process::exit(1);
             }
         },
@@ -33,6 +40,47 @@ pub fn set(key: &str, value: &str) -> Result<()> {
     Ok(())
 }
 
+/// Check `config.toml` against the keys Cori reads. Exits 1 when any
+/// issue is found so CI can gate on it; `--json` emits a structured
+/// report instead of the human listing. A file that doesn't parse is
+/// reported as a single issue with an empty key.
+pub fn validate(json_out: bool) -> Result<()> {
+    let path = paths::config_file()?;
+    let issues = match Config::load_from(&path) {
+        Ok(cfg) => cfg.validate(),
+        Err(e) => vec![ConfigIssue {
+            key: String::new(),
+            reason: format!("{e:#}"),
+        }],
+    };
+    if json_out {
+        let report = json!({
+            "path": path.display().to_string(),
+            "valid": issues.is_empty(),
+            "issues": issues
+                .iter()
+                .map(|i| json!({ "key": i.key, "reason": i.reason }))
+                .collect::<Vec<_>>(),
+        });
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else if issues.is_empty() {
+        println!("✓ {} is valid", path.display());
+    } else {
+        println!("✗ {}", path.display());
+        for i in &issues {
+            if i.key.is_empty() {
+                println!("  {}", i.reason);
+            } else {
+                println!("  {}: {}", i.key, i.reason);
+            }
+        }
+    }
+    if !issues.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
 fn render_value(v: &toml::Value) -> String {
     match v {
         toml::Value::String(s) => s.clone(),
@@ -254,7 +254,9 @@ fn login_llm_provider(provider: &'static str) -> Result<()> {
         bail!("no API key entered");
     }
 
-    crate::commands::config::set(&format!("llm.{provider}.api_key"), &key)?;
+    let mut cfg = Config::load()?;
+    cfg.set_literal(&format!("llm.{provider}.api_key"), &key)?;
+    cfg.save()?;
     println!("✓ Stored {provider} API key in ~/.cori/config.toml.");
     println!("  (To override per shell, export the matching env var instead.)");
     notify_open_workflows(provider);
@@ -37,8 +37,7 @@ use std::time::Duration;
 use anyhow::{Context, Result, anyhow, bail};
 use serde_json::{Value as JsonValue, json};
 
-use cori_broker::identity::{IdentitySource, OsUser};
-use cori_protocol::{StepKind, WorkerIdentity, task_queue_for};
+use cori_protocol::StepKind;
 
 const PROTOCOL_VERSIONS: &[&str] = &["2025-06-18", "2025-03-26", "2024-11-05"];
 const LATEST_PROTOCOL: &str = "2025-06-18";
@@ -457,26 +456,7 @@ fn tool_check(args: &JsonValue) -> Result<JsonValue> {
 
     // Trusted or local: the full per-step preflight (same code as `cori check`).
     let report = super::check::preflight(&source, update, false)?;
-    Ok(json!({
-        "ready": report.ready,
-        "endpoint": report.endpoint,
-        "temporal_reachable": report.temporal_reachable,
-        "user_task_queue": report.user_task_queue,
-        "steps": report.steps.iter().map(|s| json!({
-            "step_name": s.step_name,
-            "kind": kind_label(s.kind),
-            "task_queue": s.task_queue,
-            "missing": s.missing,
-        })).collect::<Vec<_>>(),
-        "capabilities": report.capabilities.iter().map(|c| json!({
-            "id": c.id,
-            "kind": format!("{:?}", c.kind),
-            "authed": c.authed,
-            "detail": c.detail,
-            "remedy": c.remedy,
-        })).collect::<Vec<_>>(),
-        "warnings": report.warnings,
-    }))
+    Ok(super::check::report_json(&report))
 }
 
 fn tool_show(args: &JsonValue) -> Result<JsonValue> {
@@ -596,46 +576,7 @@ fn trim_trace_outputs(trace: &mut JsonValue) {
 }
 
 fn tool_status() -> Result<JsonValue> {
-    use cori_broker::capabilities::{self, CapabilityReport};
-    use cori_run::{paths, planner, temporal_endpoint};
-
-    let endpoint = temporal_endpoint::resolve()?;
-    let reachable =
-        cori_worker::runtime::preflight_check(&endpoint.target, Duration::from_millis(500)).is_ok();
-    let identity = OsUser.resolve().context("resolving OS user identity")?;
-    let queue = task_queue_for(&identity);
-    let credentials = cori_run::resolve_llm_credentials();
-    let home = paths::home()?;
-    let caps = capabilities::discover(&home, &[], &credentials);
-    let report = CapabilityReport::from_capabilities_with(
-        identity.clone(),
-        &caps,
-        Some(&paths::credentials_dir()?),
-    );
-    let cluster = planner::ClusterView::load().unwrap_or_default();
-
-    Ok(json!({
-        "endpoint": endpoint.target,
-        "temporal_reachable": reachable,
-        "identity": match &identity {
-            WorkerIdentity::Person { user_id } => json!({ "kind": "person", "user_id": user_id }),
-            WorkerIdentity::Service { pool } => json!({ "kind": "service", "pool": pool }),
-        },
-        "user_task_queue": queue,
-        "capabilities": report.capabilities.iter().map(|c| json!({
-            "id": c.id,
-            "kind": format!("{:?}", c.kind),
-            "authed": c.authed,
-            "detail": c.detail,
-        })).collect::<Vec<_>>(),
-        "workers_seen": cluster.reports.iter().map(|r| json!({
-            "task_queue": r.task_queue,
-            "kind": match &r.identity {
-                WorkerIdentity::Person { .. } => "user",
-                WorkerIdentity::Service { .. } => "shared",
-            },
-        })).collect::<Vec<_>>(),
-    }))
+    super::status::status_json()
 }
 
 // ---------------------------------------------------------------------------
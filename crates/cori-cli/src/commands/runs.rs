@@ -1,4 +1,4 @@
-//! `cori runs list|show` — disk-backed run history.
+//! `cori runs list|show|prune` — disk-backed run history.
 //!
 //! Phase 2 reads from `~/.cori/runs/<run_history_key>/<utc>.json`.
 //! Each JSON file is a [`super::run::RunTrace`]. We discover files by
@@ -199,6 +199,59 @@ pub fn show(run_id: &str, activity_filter: Option<&str>, full: bool, json_out: b
     Ok(())
 }
 
+/// Delete traces of runs that started more than `older_than_days` ago.
+/// History directories left empty are removed too. With `dry_run`, only
+/// lists what would go.
+pub fn prune(older_than_days: u32, workflow_filter: Option<&str>, dry_run: bool) -> Result<()> {
+    // A window reaching past the earliest representable date means no
+    // run can be older than it.
+    let cutoff = Utc::now().checked_sub_signed(chrono::Duration::days(i64::from(older_than_days)));
+    let expired: Vec<RunEntry> = match cutoff {
+        Some(cutoff) => collect_runs(workflow_filter)?
+            .into_iter()
+            .filter(|e| e.trace.started_at < cutoff)
+            .collect(),
+        None => Vec::new(),
+    };
+
+    if expired.is_empty() {
+        println!("No runs older than {older_than_days} days.");
+        return Ok(());
+    }
+
+    if dry_run {
+        println!(
+            "Would prune {} run{}:",
+            expired.len(),
+            if expired.len() == 1 { "" } else { "s" }
+        );
+        for e in &expired {
+            println!(
+                "  · {}  {}  {}",
+                e.trace.started_at.format("%Y-%m-%d"),
+                e.trace.workflow_id,
+                e.trace.run_id
+            );
+        }
+        return Ok(());
+    }
+
+    for e in &expired {
+        std::fs::remove_file(&e.path)
+            .with_context(|| format!("removing `{}`", e.path.display()))?;
+        // Only succeeds once the history directory is empty.
+        if let Some(dir) = e.path.parent() {
+            let _ = std::fs::remove_dir(dir);
+        }
+    }
+    println!(
+        "✓ Pruned {} run{} older than {older_than_days} days.",
+        expired.len(),
+        if expired.len() == 1 { "" } else { "s" }
+    );
+    Ok(())
+}
+
 fn format_duration_ms(ms: u128) -> String {
     if ms < 1_000 {
         format!("{ms}ms")
@@ -4,6 +4,8 @@
 //! kind / placement / declared capability, and the most recent runs
 //! recorded under `~/.cori/runs/<key>/`.
 //!
+//! `--json` emits the same information as one JSON object for scripts.
+//!
 //! Accepts either a local path or a remote git ref. For a remote ref
 //! that has not been fetched locally, only the run history is shown
 //! (since the run-history key doesn't depend on the resolved sha).
@@ -11,16 +13,21 @@
 use anyhow::{Context, Result};
 use chrono_humanize::HumanTime;
 use cori_protocol::{Placement, RunTrace, StepKind};
+use serde_json::{Value as JsonValue, json};
 
 use cori_run::remote::{self, ArgClass};
 use cori_run::{paths, workflow_loader};
 
-pub fn show(path: String) -> Result<()> {
+pub fn show(path: String, json_out: bool) -> Result<()> {
     let class = remote::classify_arg(&path)?;
     match class {
         ArgClass::Local(p) => {
             let loaded = workflow_loader::load(&p)?;
-            print_loaded(&loaded)?;
+            if json_out {
+                println!("{}", serde_json::to_string_pretty(&loaded_json(&loaded)?)?);
+            } else {
+                print_loaded(&loaded)?;
+            }
         }
         ArgClass::Remote(spec) => {
             // Don't fetch on `show`. If a pin exists, try to load from
@@ -46,7 +53,21 @@ pub fn show(path: String) -> Result<()> {
             match cached {
                 Some(dir) => {
                     let loaded = workflow_loader::load(&dir)?;
-                    print_loaded(&loaded)?;
+                    if json_out {
+                        println!("{}", serde_json::to_string_pretty(&loaded_json(&loaded)?)?);
+                    } else {
+                        print_loaded(&loaded)?;
+                    }
+                }
+                None if json_out => {
+                    let key = remote::remote_run_history_key(&spec);
+                    let out = json!({
+                        "workflow": spec.display(),
+                        "fetched": false,
+                        "history_key": key,
+                        "recent_runs": recent_runs_json(&paths::runs_dir()?.join(&key))?,
+                    });
+                    println!("{}", serde_json::to_string_pretty(&out)?);
                 }
                 None => {
                     println!("Workflow: {}", spec.display());
@@ -95,6 +116,52 @@ fn print_loaded(loaded: &workflow_loader::LoadedWorkflow) -> Result<()> {
     Ok(())
 }
 
+fn loaded_json(loaded: &workflow_loader::LoadedWorkflow) -> Result<JsonValue> {
+    let compiled = &loaded.compiled;
+    let key = workflow_loader::loaded_run_history_key(loaded);
+    Ok(json!({
+        "workflow": compiled.manifest.id,
+        "fetched": true,
+        "path": loaded.absolute_path.display().to_string(),
+        "content_hash": loaded.content_hash,
+        "from_cache": loaded.from_cache,
+        "manifest": compiled.manifest,
+        "required": {
+            "cli": compiled.required_cli_binaries,
+            "mcp_servers": compiled.required_mcp_servers,
+            "llm_providers": compiled.required_llm_providers,
+        },
+        "steps": compiled.steps.iter().map(|s| json!({
+            "activity_id": s.activity_id,
+            "name": s.name,
+            "kind": kind_label(s.kind),
+            "placement": s.placement,
+            "description": s.description,
+        })).collect::<Vec<_>>(),
+        "history_key": key,
+        "recent_runs": recent_runs_json(&paths::runs_dir()?.join(&key))?,
+    }))
+}
+
+fn recent_runs_json(dir: &std::path::Path) -> Result<Vec<JsonValue>> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    Ok(read_runs_dir(dir)?
+        .iter()
+        .take(10)
+        .map(|(_, t)| {
+            json!({
+                "run_id": t.run_id,
+                "status": t.status,
+                "started_at": t.started_at,
+                "duration_ms": t.duration_ms,
+                "cost_eur": t.cost.total_eur,
+            })
+        })
+        .collect())
+}
+
 fn print_remote_history(spec: &remote::RemoteRef) -> Result<()> {
     let key = remote::remote_run_history_key(spec);
     let dir = paths::runs_dir()?.join(&key);
@@ -157,7 +224,8 @@ fn print_recent_runs(loaded: &workflow_loader::LoadedWorkflow) -> Result<()> {
     print_runs_dir(&dir)
 }
 
-fn print_runs_dir(dir: &std::path::Path) -> Result<()> {
+/// Every parseable trace in one run-history directory, most recent first.
+fn read_runs_dir(dir: &std::path::Path) -> Result<Vec<(std::path::PathBuf, RunTrace)>> {
     let mut entries: Vec<(std::path::PathBuf, RunTrace)> = std::fs::read_dir(dir)
         .with_context(|| format!("reading `{}`", dir.display()))?
         .flatten()
@@ -179,6 +247,11 @@ fn print_runs_dir(dir: &std::path::Path) -> Result<()> {
         })
         .collect();
     entries.sort_by_key(|(_, t)| std::cmp::Reverse(t.started_at));
+    Ok(entries)
+}
+
+fn print_runs_dir(dir: &std::path::Path) -> Result<()> {
+    let entries = read_runs_dir(dir)?;
     if entries.is_empty() {
         println!("  (no runs yet)");
         return Ok(());
@@ -15,46 +15,132 @@ use anyhow::{Context, Result};
 use cori_broker::capabilities::{self, Capability, CapabilityKind, CapabilityReport};
 use cori_broker::identity::{IdentitySource, OsUser};
 use cori_protocol::{WorkerIdentity, task_queue_for};
+use serde_json::{Value as JsonValue, json};
 
 use crate::commands::run::resolve_llm_credentials;
 use cori_run::{paths, planner, remote, temporal_endpoint};
 
-pub fn status() -> Result<()> {
-    let endpoint = temporal_endpoint::resolve()?;
-    let reachable = cori_worker::runtime::preflight_check(
-        &endpoint.target,
-        std::time::Duration::from_millis(500),
-    )
-    .is_ok();
+/// Everything `cori status` reports, gathered once and rendered either
+/// as text or as JSON.
+struct StatusView {
+    endpoint: String,
+    reachable: bool,
+    identity: WorkerIdentity,
+    queue: String,
+    report: CapabilityReport,
+    cluster: planner::ClusterView,
+    pinned: Vec<PinnedRemote>,
+}
+
+/// One pinned remote ref (`cache/remote/pins.json`) joined with its trust
+/// state and run history.
+struct PinnedRemote {
+    key: String,
+    sha: String,
+    trusted: bool,
+    run_count: usize,
+    last_run: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl StatusView {
+    fn gather() -> Result<Self> {
+        let endpoint = temporal_endpoint::resolve()?;
+        let reachable = cori_worker::runtime::preflight_check(
+            &endpoint.target,
+            std::time::Duration::from_millis(500),
+        )
+        .is_ok();
 
-    let identity = OsUser
-        .resolve()
-        .context("resolving OS user identity for `cori status`")?;
-    let queue = task_queue_for(&identity);
+        let identity = OsUser
+            .resolve()
+            .context("resolving OS user identity for `cori status`")?;
+        let queue = task_queue_for(&identity);
 
-    let credentials = resolve_llm_credentials();
-    let home = paths::home()?;
-    let caps = capabilities::discover(&home, &[], &credentials);
-    let self_report = CapabilityReport::from_capabilities_with(
-        identity.clone(),
-        &caps,
-        Some(&paths::credentials_dir()?),
-    );
+        let credentials = resolve_llm_credentials();
+        let home = paths::home()?;
+        let caps = capabilities::discover(&home, &[], &credentials);
+        let report = CapabilityReport::from_capabilities_with(
+            identity.clone(),
+            &caps,
+            Some(&paths::credentials_dir()?),
+        );
+
+        let cluster = planner::ClusterView::load().unwrap_or_default();
 
-    let cluster = planner::ClusterView::load().unwrap_or_default();
+        Ok(Self {
+            endpoint: endpoint.target,
+            reachable,
+            identity,
+            queue,
+            report,
+            cluster,
+            // Non-fatal: a corrupt pins/trust cache file must not take
+            // down the whole overview (or the MCP `status` tool).
+            pinned: pinned_remotes().unwrap_or_else(|e| {
+                tracing::warn!(error = %format!("{e:#}"), "could not read pinned remotes");
+                Vec::new()
+            }),
+        })
+    }
+
+    fn to_json(&self) -> JsonValue {
+        json!({
+            "endpoint": self.endpoint,
+            "temporal_reachable": self.reachable,
+            "identity": match &self.identity {
+                WorkerIdentity::Person { user_id } => json!({ "kind": "person", "user_id": user_id }),
+                WorkerIdentity::Service { pool } => json!({ "kind": "service", "pool": pool }),
+            },
+            "user_task_queue": self.queue,
+            "capabilities": self.report.capabilities.iter().map(|c| json!({
+                "id": c.id,
+                "kind": format!("{:?}", c.kind),
+                "authed": c.authed,
+                "detail": c.detail,
+            })).collect::<Vec<_>>(),
+            "workers_seen": self.cluster.reports.iter().map(|r| json!({
+                "task_queue": r.task_queue,
+                "kind": match &r.identity {
+                    WorkerIdentity::Person { .. } => "user",
+                    WorkerIdentity::Service { .. } => "shared",
+                },
+            })).collect::<Vec<_>>(),
+            "pinned_remotes": self.pinned.iter().map(|p| json!({
+                "key": p.key,
+                "sha": p.sha,
+                "trusted": p.trusted,
+                "run_count": p.run_count,
+                "last_run": p.last_run,
+            })).collect::<Vec<_>>(),
+        })
+    }
+}
+
+pub fn status(json_out: bool) -> Result<()> {
+    let view = StatusView::gather()?;
+    if json_out {
+        println!("{}", serde_json::to_string_pretty(&view.to_json())?);
+        return Ok(());
+    }
 
     print_header();
-    print_endpoint(&endpoint.target, reachable);
-    print_identity(&identity, &queue);
+    print_endpoint(&view.endpoint, view.reachable);
+    print_identity(&view.identity, &view.queue);
     println!();
-    print_capabilities(&self_report);
+    print_capabilities(&view.report);
     println!();
-    print_cluster(&cluster, &queue);
+    print_cluster(&view.cluster, &view.queue);
     println!();
-    print_pinned_remotes()?;
+    print_pinned_remotes(&view.pinned);
     Ok(())
 }
 
+/// Machine-readable overview. Shared by `cori status --json` and the
+/// MCP `status` tool.
+pub(crate) fn status_json() -> Result<JsonValue> {
+    Ok(StatusView::gather()?.to_json())
+}
+
 fn print_header() {
     let host = hostname().unwrap_or_else(|| "unknown".to_string());
     println!("Cori status (machine: {host})");
@@ -142,15 +228,11 @@ fn cap_kind_label(kind: CapabilityKind) -> &'static str {
     }
 }
 
-fn print_pinned_remotes() -> anyhow::Result<()> {
+fn pinned_remotes() -> Result<Vec<PinnedRemote>> {
     let pins = remote::pins::load()?;
     let trust = remote::trust::load()?;
-    println!("Pinned remote workflows:");
-    if pins.entries.is_empty() {
-        println!("  (none)");
-        return Ok(());
-    }
     let runs_root = paths::runs_dir().ok();
+    let mut out = Vec::with_capacity(pins.entries.len());
     for (key, entry) in &pins.entries {
         // key = "host/repo//subpath@ref" — try to extract the (repo, sha) trust key
         let (repo_part, _ref_part) = key.split_once('@').unwrap_or((key.as_str(), ""));
@@ -160,27 +242,44 @@ fn print_pinned_remotes() -> anyhow::Result<()> {
             .map(|(hr, _)| hr)
             .unwrap_or(repo_part);
         let trust_key = format!("{host_repo}@{}", entry.sha);
-        let trusted = trust.entries.contains_key(&trust_key);
         let (run_count, last_run) = match runs_root.as_ref() {
             Some(root) => count_runs_for(root, repo_part),
             None => (0, None),
         };
-        let trust_word = if trusted { "trusted" } else { "not trusted" };
-        let run_str = if run_count == 1 {
+        out.push(PinnedRemote {
+            key: key.clone(),
+            sha: entry.sha.clone(),
+            trusted: trust.entries.contains_key(&trust_key),
+            run_count,
+            last_run,
+        });
+    }
+    Ok(out)
+}
+
+fn print_pinned_remotes(pinned: &[PinnedRemote]) {
+    println!("Pinned remote workflows:");
+    if pinned.is_empty() {
+        println!("  (none)");
+        return;
+    }
+    for p in pinned {
+        let trust_word = if p.trusted { "trusted" } else { "not trusted" };
+        let run_str = if p.run_count == 1 {
             "1 run".to_string()
         } else {
-            format!("{run_count} runs")
+            format!("{} runs", p.run_count)
         };
-        let last_str = match last_run {
+        let last_str = match p.last_run {
             Some(when) => format!(", last run {}", chrono_humanize::HumanTime::from(when)),
             None => String::new(),
         };
         println!(
             "  {key:<60}  →  {sha}  ({trust_word}, {run_str}{last_str})",
-            sha = short_sha(&entry.sha),
+            key = p.key,
+            sha = short_sha(&p.sha),
         );
     }
-    Ok(())
 }
 
 fn short_sha(sha: &str) -> String {
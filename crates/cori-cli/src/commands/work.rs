@@ -11,7 +11,7 @@ use cori_broker::llm::LlmOptions;
 use cori_broker::{TriggerContext, runtime as broker_runtime};
 use cori_protocol::{WorkerIdentity, task_queue_for, validate_identity_token};
 use cori_worker::broker_ctx::{BrokerCtx, set_broker_ctx};
-use cori_worker::runner::serve_worker_until_signal;
+use cori_worker::runner::{DEFAULT_DRAIN_TIMEOUT, serve_worker_until_signal};
 use cori_worker::runtime::{CoriTemporalRuntime, DEFAULT_NAMESPACE, preflight_check};
 
 use crate::commands::run::resolve_llm_credentials;
@@ -80,6 +80,7 @@ pub fn work(opts: WorkOpts) -> Result<()> {
         .build()
         .context("starting tokio runtime for Temporal worker")?;
 
+    let drain_timeout = drain_timeout();
     let identity_for_cron = identity.clone();
     let serve_result = tokio_rt.block_on(async {
         let rt = CoriTemporalRuntime::connect(endpoint.target.clone(), DEFAULT_NAMESPACE, &queue)
@@ -93,17 +94,29 @@ pub fn work(opts: WorkOpts) -> Result<()> {
             .await;
         });
 
-        let result = serve_worker_until_signal(&rt).await;
-
-        let _ = cron_stop_tx.send(());
+        let cron_stop_tx = std::cell::Cell::new(Some(cron_stop_tx));
+        let stop_cron = || {
+            if let Some(tx) = cron_stop_tx.take() {
+                let _ = tx.send(());
+            }
+        };
+
+        // On the first signal, stop claiming schedule fires and stop
+        // advertising this queue to planners before the drain starts, so
+        // a dying worker picks up no new work while it finishes old work.
+        let result = serve_worker_until_signal(&rt, drain_timeout, || {
+            stop_cron();
+            unpublish(&queue);
+        })
+        .await;
+
+        stop_cron();
         let _ = cron_task.await;
 
         result
     });
 
-    if let Err(e) = planner::unpublish_report(&queue) {
-        tracing::warn!(error = %format!("{e:#}"), "could not remove capability report");
-    }
+    unpublish(&queue);
 
     serve_result?;
 
@@ -111,6 +124,23 @@ pub fn work(opts: WorkOpts) -> Result<()> {
     Ok(())
 }
 
+fn unpublish(queue: &str) {
+    if let Err(e) = planner::unpublish_report(queue) {
+        tracing::warn!(error = %format!("{e:#}"), "could not remove capability report");
+    }
+}
+
+/// How long to wait for in-flight activities on SIGINT/SIGTERM.
+/// `CORI_DRAIN_TIMEOUT_SECS` overrides the default so rolling deploys
+/// can match their orchestrator's termination grace period.
+fn drain_timeout() -> std::time::Duration {
+    std::env::var("CORI_DRAIN_TIMEOUT_SECS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .map(std::time::Duration::from_secs)
+        .unwrap_or(DEFAULT_DRAIN_TIMEOUT)
+}
+
 fn resolve_identity(shared: Option<&str>) -> Result<WorkerIdentity> {
     match shared {
         Some(name) => {
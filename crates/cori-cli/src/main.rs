@@ -42,7 +42,7 @@ enum Command {
         #[arg(value_name = "PARAM")]
         params: Vec<String>,
     },
-    /// Inspect previously recorded runs.
+    /// Inspect or prune previously recorded runs.
     Runs {
         #[command(subcommand)]
         command: RunsCommand,
@@ -96,6 +96,9 @@ enum Command {
         /// Skip the consent prompt for remote workflows.
         #[arg(long = "yes", short = 'y')]
         assume_yes: bool,
+        /// Emit the preflight report as JSON. Exit code is unchanged.
+        #[arg(long)]
+        json: bool,
     },
     /// Serve Cori over the Model Context Protocol (stdio).
     ///
@@ -107,7 +110,11 @@ enum Command {
     Mcp,
     /// Print machine-scoped overview: endpoint, identity, capabilities,
     /// and workers currently visible on the cluster.
-    Status,
+    Status {
+        /// Emit the overview as JSON.
+        #[arg(long)]
+        json: bool,
+    },
     /// Inspect a workflow folder — manifest, steps, required
     /// capabilities, and recent runs. Accepts a remote ref; if the
     /// ref has not been fetched locally, history is shown but the
@@ -115,6 +122,9 @@ enum Command {
     Show {
         /// Path to the workflow folder or remote git ref.
         path: String,
+        /// Emit the manifest, steps, and recent runs as JSON.
+        #[arg(long)]
+        json: bool,
     },
 }
 
@@ -143,6 +153,19 @@ enum RunsCommand {
         #[arg(long)]
         json: bool,
     },
+    /// Delete run traces older than a retention window.
+    Prune {
+        /// Retention window: runs that started more than this many days
+        /// ago are deleted. Must be at least 1.
+        #[arg(long, value_name = "DAYS", value_parser = clap::value_parser!(u32).range(1..))]
+        older_than: u32,
+        /// Restrict to runs of one workflow.
+        #[arg(long)]
+        workflow_id: Option<String>,
+        /// List what would be deleted without deleting anything.
+        #[arg(long)]
+        dry_run: bool,
+    },
 }
 
 #[derive(Debug, Subcommand)]
@@ -167,6 +190,12 @@ enum ConfigCommand {
     /// Write a key. Values are auto-coerced to bool/int/float when they
     /// parse cleanly; otherwise stored as a string.
     Set { key: String, value: String },
+    /// Check every key against what Cori reads: unknown keys and wrong
+    /// value types are reported and the exit code is `1`.
+    Validate {
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 fn main() -> anyhow::Result<()> {
@@ -190,6 +219,7 @@ fn main() -> anyhow::Result<()> {
         Some(Command::Config { command }) => match command {
             ConfigCommand::Get { key } => commands::config::get(key.as_deref()),
             ConfigCommand::Set { key, value } => commands::config::set(&key, &value),
+            ConfigCommand::Validate { json } => commands::config::validate(json),
         },
         Some(Command::Run {
             path,
@@ -211,6 +241,11 @@ fn main() -> anyhow::Result<()> {
                 full,
                 json,
             } => commands::runs::show(&run_id, activity.as_deref(), full, json),
+            RunsCommand::Prune {
+                older_than,
+                workflow_id,
+                dry_run,
+            } => commands::runs::prune(older_than, workflow_id.as_deref(), dry_run),
         },
         Some(Command::Work { shared }) => commands::work::work(commands::work::WorkOpts { shared }),
         Some(Command::Login { capability }) => commands::login::login(&capability),
@@ -222,9 +257,10 @@ fn main() -> anyhow::Result<()> {
             path,
             update,
             assume_yes,
-        }) => commands::check::check(path, update, assume_yes),
+            json,
+        }) => commands::check::check(path, update, assume_yes, json),
         Some(Command::Mcp) => commands::mcp::mcp(),
-        Some(Command::Status) => commands::status::status(),
-        Some(Command::Show { path }) => commands::show::show(path),
+        Some(Command::Status { json }) => commands::status::status(json),
+        Some(Command::Show { path, json }) => commands::show::show(path, json),
     }
 }
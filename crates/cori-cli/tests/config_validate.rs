@@ -0,0 +1,65 @@
+//! Integration tests for `cori config validate` (and `get` on a broken
+//! reference) — drive the real binary against a fake `CORI_HOME` holding a
+//! fixture `config.toml`.
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+use serde_json::Value as JsonValue;
+
+fn validate_json(home: &Path, config: &str) -> (Output, JsonValue) {
+    std::fs::write(home.join("config.toml"), config).unwrap();
+    let out = Command::new(env!("CARGO_BIN_EXE_cori"))
+        .args(["config", "validate", "--json"])
+        .env("CORI_HOME", home)
+        .output()
+        .expect("spawning `cori config validate`");
+    let v = serde_json::from_slice(&out.stdout).expect("stdout is JSON");
+    (out, v)
+}
+
+#[test]
+fn valid_config_exits_zero() {
+    let home = tempfile::tempdir().unwrap();
+    let (out, v) = validate_json(home.path(), "[temporal]\nhost = \"http://10.0.0.5:7233\"\n");
+    assert!(out.status.success());
+    assert_eq!(v["valid"], true);
+}
+
+#[test]
+fn syntax_error_is_reported_as_an_issue() {
+    let home = tempfile::tempdir().unwrap();
+    let (out, v) = validate_json(home.path(), "[llm.openai]\napi_key = sk-live-secret\n");
+    assert_eq!(out.status.code(), Some(1));
+    assert_eq!(v["valid"], false);
+    let issues = v["issues"].as_array().unwrap();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0]["key"], "");
+    let reason = issues[0]["reason"].as_str().unwrap();
+    assert!(reason.contains("parsing TOML"), "{reason}");
+    assert!(reason.contains("line 2"), "{reason}");
+    // The offending line may be a secret; only its position is reported.
+    assert!(!reason.contains("sk-live-secret"), "{reason}");
+}
+
+#[test]
+fn get_explains_an_unresolved_reference() {
+    let home = tempfile::tempdir().unwrap();
+    std::fs::write(
+        home.path().join("config.toml"),
+        "[temporal]\nhost = \"${CORI_TEST_SURELY_UNSET_HOST}\"\n",
+    )
+    .unwrap();
+    let out = Command::new(env!("CARGO_BIN_EXE_cori"))
+        .args(["config", "get", "temporal.host"])
+        .env("CORI_HOME", home.path())
+        .env_remove("CORI_TEST_SURELY_UNSET_HOST")
+        .output()
+        .expect("spawning `cori config get`");
+    assert_eq!(out.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        stderr.contains("`CORI_TEST_SURELY_UNSET_HOST` is not set"),
+        "{stderr}"
+    );
+}
@@ -0,0 +1,144 @@
+//! Integration tests for the `--json` outputs of `cori show`, `cori check`
+//! and `cori status` — drive the real binary against a fake `CORI_HOME`.
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use chrono::Utc;
+use serde_json::{Value as JsonValue, json};
+
+/// Nothing listens on port 1, so preflight fails fast and nothing tries
+/// to auto-spawn a dev server.
+const UNREACHABLE_TEMPORAL: &str = "http://127.0.0.1:1";
+
+fn examples_dir(name: &str) -> String {
+    // crates/cori-cli → ../../examples/<name>
+    let mut p = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    p.pop();
+    p.pop();
+    p.push("examples");
+    p.push(name);
+    p.display().to_string()
+}
+
+fn cori(home: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_cori"))
+        .args(args)
+        .env("CORI_HOME", home)
+        .env("CORI_TEMPORAL_TARGET", UNREACHABLE_TEMPORAL)
+        // Any existing file satisfies runtime resolution; nothing is spawned.
+        .env("CORI_DENO", std::env::current_exe().unwrap())
+        .output()
+        .expect("spawning `cori`")
+}
+
+fn stdout_json(out: &Output) -> JsonValue {
+    serde_json::from_slice(&out.stdout).unwrap_or_else(|e| {
+        panic!(
+            "stdout is not JSON ({e}):\n{}\nstderr:\n{}",
+            String::from_utf8_lossy(&out.stdout),
+            String::from_utf8_lossy(&out.stderr)
+        )
+    })
+}
+
+#[test]
+fn show_json_reports_workflow_and_recent_runs() {
+    let home = tempfile::tempdir().unwrap();
+    let path = examples_dir("hello_world");
+
+    let out = cori(home.path(), &["show", "--json", &path]);
+    assert!(out.status.success());
+    let v = stdout_json(&out);
+    assert_eq!(v["workflow"], "hello_world");
+    assert_eq!(v["fetched"], true);
+    assert!(v["manifest"].is_object());
+    assert_eq!(v["steps"].as_array().map(Vec::len), Some(3));
+    assert_eq!(v["required"]["cli"], json!(["curl"]));
+    assert_eq!(v["recent_runs"], json!([]));
+    let key = v["history_key"].as_str().expect("history_key").to_string();
+
+    let started_at = Utc::now();
+    let trace = cori_protocol::RunTrace {
+        run_id: "run-1".into(),
+        workflow_id: "hello_world".into(),
+        workflow_content_hash: None,
+        status: "succeeded".into(),
+        trigger: "cli".into(),
+        dry_run: false,
+        requesting_identity: None,
+        started_at,
+        ended_at: started_at,
+        duration_ms: 42,
+        source: None,
+        params: json!({}),
+        activities: Vec::new(),
+        cost: cori_protocol::CostSummary::default(),
+        error: None,
+    };
+    let dir = home.path().join("runs").join(&key);
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("run-1.json"), serde_json::to_vec(&trace).unwrap()).unwrap();
+
+    let v = stdout_json(&cori(home.path(), &["show", "--json", &path]));
+    let runs = v["recent_runs"].as_array().unwrap();
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0]["run_id"], "run-1");
+    assert_eq!(runs[0]["status"], "succeeded");
+    assert_eq!(runs[0]["duration_ms"], 42);
+}
+
+#[test]
+fn check_json_keeps_exit_code_2_when_not_ready() {
+    let home = tempfile::tempdir().unwrap();
+    let out = cori(
+        home.path(),
+        &["check", "--json", &examples_dir("code_only")],
+    );
+    assert_eq!(out.status.code(), Some(2));
+    let v = stdout_json(&out);
+    assert_eq!(v["ready"], false);
+    assert_eq!(v["temporal_reachable"], false);
+    assert_eq!(v["endpoint"], UNREACHABLE_TEMPORAL);
+    assert!(v["steps"].as_array().is_some_and(|s| !s.is_empty()));
+}
+
+#[test]
+fn status_json_includes_pinned_remotes() {
+    let home = tempfile::tempdir().unwrap();
+    let pins = home.path().join("cache").join("remote");
+    std::fs::create_dir_all(&pins).unwrap();
+    std::fs::write(
+        pins.join("pins.json"),
+        r#"{ "github.com/org/workflows//translate@v1": "0123456789abcdef" }"#,
+    )
+    .unwrap();
+
+    let out = cori(home.path(), &["status", "--json"]);
+    assert!(out.status.success());
+    let v = stdout_json(&out);
+    assert_eq!(v["temporal_reachable"], false);
+    assert!(v["capabilities"].is_array());
+    assert_eq!(
+        v["pinned_remotes"],
+        json!([{
+            "key": "github.com/org/workflows//translate@v1",
+            "sha": "0123456789abcdef",
+            "trusted": false,
+            "run_count": 0,
+            "last_run": null,
+        }])
+    );
+}
+
+#[test]
+fn status_json_survives_a_corrupt_pins_file() {
+    let home = tempfile::tempdir().unwrap();
+    let pins = home.path().join("cache").join("remote");
+    std::fs::create_dir_all(&pins).unwrap();
+    std::fs::write(pins.join("pins.json"), "{ not json").unwrap();
+
+    let out = cori(home.path(), &["status", "--json"]);
+    assert!(out.status.success());
+    assert_eq!(stdout_json(&out)["pinned_remotes"], json!([]));
+}
@@ -0,0 +1,92 @@
+//! Integration test for `cori runs prune` — drive the real binary
+//! against a fake `CORI_HOME` holding fixture traces.
+
+use std::path::Path;
+use std::process::Command;
+
+use chrono::{DateTime, Utc};
+use serde_json::json;
+
+fn write_trace(dir: &Path, run_id: &str, started_at: DateTime<Utc>) -> std::path::PathBuf {
+    let trace = cori_protocol::RunTrace {
+        run_id: run_id.into(),
+        workflow_id: "fixture_wf".into(),
+        workflow_content_hash: None,
+        status: "succeeded".into(),
+        trigger: "cli".into(),
+        dry_run: false,
+        requesting_identity: None,
+        started_at,
+        ended_at: started_at,
+        duration_ms: 1,
+        source: None,
+        params: json!({}),
+        activities: Vec::new(),
+        cost: cori_protocol::CostSummary::default(),
+        error: None,
+    };
+    std::fs::create_dir_all(dir).unwrap();
+    let path = dir.join(format!("{run_id}.json"));
+    std::fs::write(&path, serde_json::to_vec(&trace).unwrap()).unwrap();
+    path
+}
+
+fn prune(home: &Path, extra: &[&str]) {
+    let status = Command::new(env!("CARGO_BIN_EXE_cori"))
+        .args(["runs", "prune", "--older-than", "30"])
+        .args(extra)
+        .env("CORI_HOME", home)
+        .status()
+        .expect("spawning `cori runs prune`");
+    assert!(status.success());
+}
+
+#[test]
+fn prune_deletes_only_runs_past_the_retention_window() {
+    let home = tempfile::tempdir().unwrap();
+    let now = Utc::now();
+    let old_dir = home.path().join("runs").join("old_wf-00000000");
+    let live_dir = home.path().join("runs").join("fixture_wf-00000000");
+    let old = write_trace(&old_dir, "run-old", now - chrono::Duration::days(40));
+    let fresh = write_trace(&live_dir, "run-fresh", now);
+    let stale = write_trace(&live_dir, "run-stale", now - chrono::Duration::days(31));
+
+    prune(home.path(), &["--dry-run"]);
+    assert!(old.exists() && fresh.exists() && stale.exists());
+
+    prune(home.path(), &[]);
+    assert!(!old.exists());
+    assert!(!stale.exists());
+    assert!(fresh.exists());
+    // Emptied history directories go too; live ones stay.
+    assert!(!old_dir.exists());
+    assert!(live_dir.exists());
+}
+
+#[test]
+fn prune_window_past_the_earliest_date_keeps_everything() {
+    let home = tempfile::tempdir().unwrap();
+    let dir = home.path().join("runs").join("fixture_wf-00000000");
+    let old = write_trace(&dir, "run-old", Utc::now() - chrono::Duration::days(400));
+    let status = Command::new(env!("CARGO_BIN_EXE_cori"))
+        .args(["runs", "prune", "--older-than", "100000000"])
+        .env("CORI_HOME", home.path())
+        .status()
+        .expect("spawning `cori runs prune`");
+    assert!(status.success());
+    assert!(old.exists());
+}
+
+#[test]
+fn prune_rejects_a_zero_day_window() {
+    let home = tempfile::tempdir().unwrap();
+    let dir = home.path().join("runs").join("fixture_wf-00000000");
+    let fresh = write_trace(&dir, "run-fresh", Utc::now() - chrono::Duration::minutes(5));
+    let out = Command::new(env!("CARGO_BIN_EXE_cori"))
+        .args(["runs", "prune", "--older-than", "0"])
+        .env("CORI_HOME", home.path())
+        .output()
+        .expect("spawning `cori runs prune`");
+    assert!(!out.status.success());
+    assert!(fresh.exists());
+}
@@ -118,10 +118,22 @@ pub fn parse(source: &str) -> Result<ParsedStep, Vec<ParseError>> {
 
     // 4. Kind-agnostic scalar metadata shared by every step kind
     //    (`BaseStepOpts` in the SDK).
+    // Only the options object's own `retries` / `timeout_ms` count — the
+    // same keys nested in `args`, a `run` result, or `wait`'s `for` are
+    // not the activity's retry policy or per-attempt timeout.
+    let top_level = top_level_only(args_span);
     let mut metadata = JsonMap::new();
-    if let Some(retries) = extract_retries_field(args_span) {
+    if let Some(retries) = extract_retries_field(args_span, &top_level) {
         metadata.insert("retries".into(), JsonValue::Object(retries));
     }
+    match extract_number_field(&top_level, "timeout_ms") {
+        Some(0) => errors
+            .push(ParseError::new("`timeout_ms` must be greater than zero").field("timeout_ms")),
+        Some(ms) => {
+            metadata.insert("timeout_ms".into(), JsonValue::Number(ms.into()));
+        }
+        None => {}
+    }
 
     // 5. Kind-specific scalar metadata.
     match kind {
@@ -290,6 +302,53 @@ fn extract_balanced(s: &str, open_at: usize, open: char, close: char) -> Option<
     None
 }
 
+/// Blank out everything nested deeper than the options object itself
+/// (`{ a: 1, b: { c: 2 } }` keeps `a` but not `c`), so field regexes only
+/// see the object's own keys. Byte offsets are preserved.
+fn top_level_only(args: &str) -> String {
+    let bytes = args.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut depth: i32 = 0;
+    let mut in_str: Option<u8> = None;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        let keep = if let Some(q) = in_str {
+            if b == b'\\' && i + 1 < bytes.len() {
+                let keep = depth <= 1;
+                for &c in &bytes[i..i + 2] {
+                    out.push(if keep { c } else { b' ' });
+                }
+                i += 2;
+                continue;
+            }
+            if b == q {
+                in_str = None;
+            }
+            depth <= 1
+        } else {
+            match b {
+                b'"' | b'\'' | b'`' => {
+                    in_str = Some(b);
+                    depth <= 1
+                }
+                b'(' | b'[' | b'{' => {
+                    depth += 1;
+                    depth <= 1
+                }
+                b')' | b']' | b'}' => {
+                    depth -= 1;
+                    depth < 1
+                }
+                _ => depth <= 1,
+            }
+        };
+        out.push(if keep || b == b'\n' { b } else { b' ' });
+        i += 1;
+    }
+    String::from_utf8(out).unwrap_or_else(|_| args.to_string())
+}
+
 /// Extract a top-level `field: "literal"` from an options-object body.
 /// Only matches direct string literals (single, double, or template with no
 /// interpolation).
@@ -304,6 +363,18 @@ fn extract_string_field(body: &str, field: &str) -> Option<String> {
     Some(unquote(raw))
 }
 
+/// Extract a non-negative integer literal field (`timeout_ms: 30_000`).
+/// Numeric separators are accepted, as in TypeScript.
+fn extract_number_field(body: &str, field: &str) -> Option<u64> {
+    let pattern = format!(
+        r#"(?m)(^|[\s,{{])\s*{field}\s*:\s*(\d[\d_]*)\s*([,\n}}]|$)"#,
+        field = regex::escape(field)
+    );
+    let re = Regex::new(&pattern).ok()?;
+    let cap = re.captures(body)?;
+    cap.get(2)?.as_str().replace('_', "").parse().ok()
+}
+
 fn unquote(raw: &str) -> String {
     let inner = &raw[1..raw.len() - 1];
     let mut out = String::with_capacity(inner.len());
@@ -570,15 +641,20 @@ fn extract_batch_field(body: &str) -> Option<(u64, String)> {
     Some((size, by))
 }
 
-/// Extract a `retries: { max: N, backoff: "exponential" | "linear" }`
-/// declaration from any step's options object (`BaseStepOpts` in the SDK).
-/// `max` is required for the field to be recognised; `backoff` is optional.
-/// The emitted metadata mirrors the SDK field names so the worker can read
-/// `retries.max` / `retries.backoff` directly.
-fn extract_retries_field(body: &str) -> Option<JsonMap<String, JsonValue>> {
-    let key_re = Regex::new(r"(?m)(^|[\s,{])\s*retries\s*:\s*\{").expect("static regex");
-    let m = key_re.find(body)?;
-    let brace_at = body[..m.end()].rfind('{')?;
+/// Extract a `retries: { max: N, backoff: "exponential" | "linear",
+/// backoff_ms: N }` declaration from any step's options object
+/// (`BaseStepOpts` in the SDK). `max` is required for the field to be
+/// recognised; `backoff` and `backoff_ms` are optional. The emitted
+/// metadata mirrors the SDK field names so the worker can read
+/// `retries.max` / `retries.backoff` / `retries.backoff_ms` directly.
+///
+/// The key is looked up in `top_level` ([`top_level_only`] of `body`,
+/// same byte offsets) and its object read back from `body`.
+fn extract_retries_field(body: &str, top_level: &str) -> Option<JsonMap<String, JsonValue>> {
+    let key_re = Regex::new(r"(?m)(^|[\s,{])\s*retries\s*:").expect("static regex");
+    let m = key_re.find(top_level)?;
+    let value = &body[m.end()..];
+    let brace_at = m.end() + (value.len() - value.trim_start().len());
     let inner = extract_balanced(body, brace_at, '{', '}')?;
     let max_re = Regex::new(r#"(?m)(^|[\s,{])\s*max\s*:\s*(\d+)"#).expect("static regex");
     let max = max_re
@@ -590,6 +666,9 @@ fn extract_retries_field(body: &str) -> Option<JsonMap<String, JsonValue>> {
     if let Some(backoff) = extract_string_field(inner, "backoff") {
         out.insert("backoff".into(), JsonValue::String(backoff));
     }
+    if let Some(ms) = extract_number_field(inner, "backoff_ms") {
+        out.insert("backoff_ms".into(), JsonValue::Number(ms.into()));
+    }
     Some(out)
 }
 
@@ -721,6 +800,61 @@ mod tests {
         assert!(retries.get("backoff").is_none());
     }
 
+    #[test]
+    fn retries_backoff_ms_extracted() {
+        let src = "import { step } from \"@cori-do/sdk\";\nexport default step.mcp_tool({ description: \"x\", server: \"s\", tool: \"t\", retries: { max: 4, backoff_ms: 2_500 }, args: () => ({}) });";
+        let p = parse(src).unwrap();
+        let retries = p.metadata.get("retries").unwrap().as_object().unwrap();
+        assert_eq!(retries.get("max").unwrap(), 4);
+        assert_eq!(retries.get("backoff_ms").unwrap(), 2500);
+        assert!(p.metadata.get("timeout_ms").is_none());
+    }
+
+    #[test]
+    fn timeout_ms_extracted() {
+        let src = "import { step } from \"@cori-do/sdk\";\nexport default step.cli({\n  description: \"x\",\n  timeout_ms: 120_000,\n  command: () => [\"echo\"],\n});";
+        let p = parse(src).unwrap();
+        assert_eq!(p.metadata.get("timeout_ms").unwrap(), 120000);
+    }
+
+    #[test]
+    fn nested_timeout_ms_is_ignored() {
+        let mcp = "import { step } from \"@cori-do/sdk\";\nexport default step.mcp_tool({ description: \"x\", server: \"s\", tool: \"t\", args: (i) => ({ q: i.q, timeout_ms: 5000 }) });";
+        assert!(parse(mcp).unwrap().metadata.get("timeout_ms").is_none());
+        let code = "import { step } from \"@cori-do/sdk\";\nexport default step.code({\n  description: \"x\",\n  run: (x) => {\n    return { timeout_ms: 10 };\n  },\n});";
+        assert!(parse(code).unwrap().metadata.get("timeout_ms").is_none());
+        let both = "import { step } from \"@cori-do/sdk\";\nexport default step.code({ description: \"x\", run: (x) => ({ timeout_ms: 10 }), timeout_ms: 45_000 });";
+        assert_eq!(
+            parse(both).unwrap().metadata.get("timeout_ms").unwrap(),
+            45000
+        );
+    }
+
+    #[test]
+    fn nested_retries_is_ignored() {
+        let mcp = "import { step } from \"@cori-do/sdk\";\nexport default step.mcp_tool({ description: \"x\", server: \"s\", tool: \"t\", args: (i) => ({ retries: { max: 10, backoff_ms: 5 } }) });";
+        assert!(parse(mcp).unwrap().metadata.get("retries").is_none());
+        let both = "import { step } from \"@cori-do/sdk\";\nexport default step.code({ description: \"x\", run: (x) => ({ retries: { max: 10 } }), retries: { max: 2, backoff_ms: 500 } });";
+        let p = parse(both).unwrap();
+        let retries = p.metadata.get("retries").unwrap().as_object().unwrap();
+        assert_eq!(retries.get("max").unwrap(), 2);
+        assert_eq!(retries.get("backoff_ms").unwrap(), 500);
+    }
+
+    #[test]
+    fn zero_timeout_ms_rejected() {
+        let src = "import { step } from \"@cori-do/sdk\";\nexport default step.cli({ description: \"x\", timeout_ms: 0, command: () => [\"echo\"] });";
+        let errs = parse(src).unwrap_err();
+        assert_eq!(errs[0].field.as_deref(), Some("timeout_ms"));
+    }
+
+    #[test]
+    fn wait_timeout_is_not_an_activity_timeout() {
+        let src = "import { step } from \"@cori-do/sdk\";\nexport default step.wait({ description: \"x\", for: { signal: \"approved\", timeout_ms: 86_400_000 } });";
+        let p = parse(src).unwrap();
+        assert!(p.metadata.get("timeout_ms").is_none());
+    }
+
     #[test]
     fn no_retries_field_means_no_metadata() {
         let src = "import { step } from \"@cori-do/sdk\";\nexport default step.code({ description: \"x\", run: (x) => x });";
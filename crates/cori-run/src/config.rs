@@ -1,4 +1,10 @@
 //! `~/.cori/config.toml` reader/writer.
+//!
+//! String values may reference the environment: `${VAR}` expands to
+//! `$VAR`, `${VAR:-default}` falls back to `default` when `VAR` is unset
+//! or empty, and `$${` is a literal `${`. Expansion happens on read only —
+//! `save` writes the references back untouched, so `cori config set`
+//! never bakes a secret into the file.
 
 use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
@@ -10,9 +16,47 @@ use crate::paths;
 
 pub struct Config {
     path: PathBuf,
+    /// The document as written on disk. `set` edits it; `save` writes it.
     doc: Value,
+    /// `doc` with `${VAR}` references expanded. `get` and `flatten` read
+    /// this. Keys whose references can't expand are left out, so readers
+    /// see them as unset.
+    resolved: Value,
+    /// Why each key missing from `resolved` was dropped.
+    unresolved: Vec<ConfigIssue>,
 }
 
+/// One problem reported by [`Config::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigIssue {
+    pub key: String,
+    pub reason: String,
+}
+
+/// Value shape a known key must have.
+#[derive(Debug, Clone, Copy)]
+enum Shape {
+    String,
+    StringArray,
+}
+
+/// Every key some part of Cori reads. A `*` segment matches any single
+/// segment (e.g. a capability id). Keep in sync with the readers:
+/// `temporal_endpoint`, `resolve_llm_credentials`, `remote`, `cori login`,
+/// and the Console launcher.
+const KNOWN_KEYS: &[(&str, Shape)] = &[
+    ("temporal.host", Shape::String),
+    ("llm.openai.api_key", Shape::String),
+    ("llm.anthropic.api_key", Shape::String),
+    ("llm.gemini.api_key", Shape::String),
+    ("remotes.hosts", Shape::StringArray),
+    ("capability.*.oauth_client_id", Shape::String),
+    ("capability.*.oauth_client_secret", Shape::String),
+    ("capability.*.oauth_project_id", Shape::String),
+    ("capability.*.services", Shape::String),
+    ("launcher.last_local_dir", Shape::String),
+];
+
 impl Config {
     pub fn load() -> Result<Self> {
         Self::load_from(&paths::config_file()?)
@@ -22,15 +66,34 @@ impl Config {
         let doc = if path.exists() {
             let src = std::fs::read_to_string(path)
                 .with_context(|| format!("reading `{}`", path.display()))?;
-            toml::from_str::<Value>(&src)
-                .with_context(|| format!("parsing TOML in `{}`", path.display()))?
+            toml::from_str::<Value>(&src).map_err(|e| {
+                anyhow!(
+                    "parsing TOML in `{}`: {}",
+                    path.display(),
+                    describe_toml_error(&src, &e)
+                )
+            })?
         } else {
             Value::Table(toml::map::Map::new())
         };
-        Ok(Self {
-            path: path.to_path_buf(),
+        Ok(Self::from_doc(path.to_path_buf(), doc, &env_var))
+    }
+
+    fn from_doc(path: PathBuf, doc: Value, env: &dyn Fn(&str) -> Option<String>) -> Self {
+        let mut cfg = Self {
+            path,
             doc,
-        })
+            resolved: Value::Table(toml::map::Map::new()),
+            unresolved: Vec::new(),
+        };
+        cfg.resolve(env);
+        cfg
+    }
+
+    fn resolve(&mut self, env: &dyn Fn(&str) -> Option<String>) {
+        self.unresolved.clear();
+        self.resolved = resolve_value(&self.doc, "", env, &mut self.unresolved)
+            .unwrap_or_else(|| Value::Table(toml::map::Map::new()));
     }
 
     pub fn save(&self) -> Result<()> {
@@ -44,9 +107,10 @@ impl Config {
         Ok(())
     }
 
-    /// Look up a dotted key like `temporal.host`.
+    /// Look up a dotted key like `temporal.host`, with `${VAR}`
+    /// references expanded.
     pub fn get(&self, key: &str) -> Option<&Value> {
-        let mut cur = &self.doc;
+        let mut cur = &self.resolved;
         for segment in key.split('.') {
             cur = cur.as_table()?.get(segment)?;
         }
@@ -55,11 +119,28 @@ impl Config {
 
     /// Set a dotted key to a string value. Creates intermediate tables.
     pub fn set(&mut self, key: &str, value: &str) -> Result<()> {
+        self.insert(key, parse_value(value))
+    }
+
+    /// Set a dotted key to `value` verbatim: always a string, and any
+    /// `${` escaped so it is never expanded. For values Cori writes on the
+    /// user's behalf (a picked directory, a pasted API key), where a
+    /// literal `${` is data, not a reference.
+    pub fn set_literal(&mut self, key: &str, value: &str) -> Result<()> {
+        self.insert(key, Value::String(value.replace("${", "$${")))
+    }
+
+    /// Why `key` is set in the file but missing from [`Config::get`] —
+    /// a `${VAR}` reference that couldn't expand.
+    pub fn unresolved(&self, key: &str) -> Option<&ConfigIssue> {
+        self.unresolved.iter().find(|i| i.key == key)
+    }
+
+    fn insert(&mut self, key: &str, parsed: Value) -> Result<()> {
         let segments: Vec<&str> = key.split('.').collect();
         if segments.iter().any(|s| s.is_empty()) {
             bail!("invalid config key `{key}`");
         }
-        let parsed = parse_value(value);
         let mut cur = &mut self.doc;
         for seg in &segments[..segments.len() - 1] {
             if !cur.is_table() {
@@ -79,14 +160,190 @@ impl Config {
             .as_table_mut()
             .ok_or_else(|| anyhow!("config root is not a table"))?;
         table.insert((*last).to_string(), parsed);
+        self.resolve(&env_var);
         Ok(())
     }
 
+    /// Every resolved leaf value, keyed by dotted path.
     pub fn flatten(&self) -> BTreeMap<String, Value> {
         let mut out = BTreeMap::new();
-        flatten_into(&self.doc, String::new(), &mut out);
+        flatten_into(&self.resolved, String::new(), &mut out);
         out
     }
+
+    /// Check every key against the keys Cori actually reads. Readers
+    /// skip values they can't use (a typo'd key, `remotes.hosts` set to
+    /// a plain string by `cori config set`), so without this a broken
+    /// setting is indistinguishable from an unset one. The same goes for
+    /// a `${VAR}` reference to an unset variable.
+    pub fn validate(&self) -> Vec<ConfigIssue> {
+        let mut raw = BTreeMap::new();
+        flatten_into(&self.doc, String::new(), &mut raw);
+        let mut issues = Vec::new();
+        for key in raw.into_keys() {
+            let Some((_, shape)) = KNOWN_KEYS
+                .iter()
+                .find(|(pattern, _)| key_matches(pattern, &key))
+            else {
+                issues.push(ConfigIssue {
+                    key,
+                    reason: "unknown key — no part of Cori reads it".to_string(),
+                });
+                continue;
+            };
+            let Some(value) = self.get(&key) else {
+                if let Some(issue) = self.unresolved(&key) {
+                    issues.push(issue.clone());
+                }
+                continue;
+            };
+            let problem = match shape {
+                Shape::String => match value {
+                    Value::String(s) if s.trim().is_empty() => Some("must not be empty".into()),
+                    Value::String(_) => None,
+                    other => Some(format!("expected a string, found {}", other.type_str())),
+                },
+                Shape::StringArray => match value {
+                    Value::Array(items) if items.iter().all(Value::is_str) => None,
+                    Value::Array(_) => Some("expected an array of strings".into()),
+                    other => Some(format!(
+                        "expected an array of strings, found {} — edit `{}` directly, \
+                         e.g. `hosts = [\"git.example.com\"]`",
+                        other.type_str(),
+                        self.path.display()
+                    )),
+                },
+            };
+            if let Some(reason) = problem {
+                issues.push(ConfigIssue { key, reason });
+            }
+        }
+        issues
+    }
+}
+
+fn key_matches(pattern: &str, key: &str) -> bool {
+    let mut p = pattern.split('.');
+    let mut k = key.split('.');
+    loop {
+        match (p.next(), k.next()) {
+            (None, None) => return true,
+            (Some("*"), Some(_)) => {}
+            (Some(a), Some(b)) if a == b => {}
+            _ => return false,
+        }
+    }
+}
+
+/// Position + message of a TOML parse error, without the source excerpt
+/// toml's `Display` adds — the offending line may hold an API key.
+fn describe_toml_error(src: &str, e: &toml::de::Error) -> String {
+    let message = e.message().trim();
+    match e.span() {
+        Some(span) => {
+            let before = &src[..span.start.min(src.len())];
+            let line = before.matches('\n').count() + 1;
+            let column = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+            format!("line {line}, column {column}: {message}")
+        }
+        None => message.to_string(),
+    }
+}
+
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name).ok()
+}
+
+/// Expand every string under `v`. Returns `None` (and records an issue
+/// against `key`) when a reference can't expand; a table drops such
+/// children, anything else drops as a whole.
+fn resolve_value(
+    v: &Value,
+    key: &str,
+    env: &dyn Fn(&str) -> Option<String>,
+    issues: &mut Vec<ConfigIssue>,
+) -> Option<Value> {
+    match v {
+        Value::Table(t) => {
+            let mut out = toml::map::Map::new();
+            for (k, child) in t {
+                let child_key = if key.is_empty() {
+                    k.clone()
+                } else {
+                    format!("{key}.{k}")
+                };
+                if let Some(resolved) = resolve_value(child, &child_key, env, issues) {
+                    out.insert(k.clone(), resolved);
+                }
+            }
+            Some(Value::Table(out))
+        }
+        Value::Array(items) => {
+            let mut out = Vec::with_capacity(items.len());
+            for item in items {
+                // Array elements report against the array's key.
+                out.push(resolve_value(item, key, env, issues)?);
+            }
+            Some(Value::Array(out))
+        }
+        Value::String(s) => match interpolate(s, env) {
+            Ok(expanded) => Some(Value::String(expanded)),
+            Err(reason) => {
+                issues.push(ConfigIssue {
+                    key: key.to_string(),
+                    reason,
+                });
+                None
+            }
+        },
+        other => Some(other.clone()),
+    }
+}
+
+/// Expand `${VAR}` / `${VAR:-default}` in `s`. `$${` is a literal `${`;
+/// any other `$` is kept as-is. Errors never quote `s` — it may be a
+/// secret, and `config validate --json` output ends up in CI logs.
+fn interpolate(s: &str, env: &dyn Fn(&str) -> Option<String>) -> Result<String, String> {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(pos) = rest.find('$') {
+        out.push_str(&rest[..pos]);
+        let after = &rest[pos + 1..];
+        if let Some(escaped) = after.strip_prefix("${") {
+            out.push_str("${");
+            rest = escaped;
+        } else if let Some(body) = after.strip_prefix('{') {
+            let end = body
+                .find('}')
+                .ok_or_else(|| "unterminated `${` reference".to_string())?;
+            let (name, default) = match body[..end].split_once(":-") {
+                Some((name, default)) => (name, Some(default)),
+                None => (&body[..end], None),
+            };
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                return Err(
+                    "invalid variable name in `${...}` reference (allowed: A-Z, a-z, 0-9, `_`)"
+                        .to_string(),
+                );
+            }
+            match (
+                env(name).filter(|v| !v.is_empty() || default.is_none()),
+                default,
+            ) {
+                (Some(value), _) => out.push_str(&value),
+                (None, Some(default)) => out.push_str(default),
+                (None, None) => {
+                    return Err(format!("environment variable `{name}` is not set"));
+                }
+            }
+            rest = &body[end + 1..];
+        } else {
+            out.push('$');
+            rest = after;
+        }
+    }
+    out.push_str(rest);
+    Ok(out)
 }
 
 fn flatten_into(v: &Value, prefix: String, out: &mut BTreeMap<String, Value>) {
@@ -120,3 +377,168 @@ fn parse_value(raw: &str) -> Value {
     }
     Value::String(raw.to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(src: &str) -> Config {
+        config_with_env(src, &[])
+    }
+
+    fn config_with_env(src: &str, vars: &[(&str, &str)]) -> Config {
+        let vars: BTreeMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        Config::from_doc(
+            PathBuf::from("config.toml"),
+            toml::from_str(src).unwrap(),
+            &move |name| vars.get(name).cloned(),
+        )
+    }
+
+    #[test]
+    fn validate_accepts_known_keys() {
+        let cfg = config(
+            r#"
+            [temporal]
+            host = "http://10.0.0.5:7233"
+            [remotes]
+            hosts = ["git.example.com"]
+            [capability.gws]
+            oauth_client_id = "id"
+            services = "drive,sheets"
+            "#,
+        );
+        assert!(cfg.validate().is_empty());
+    }
+
+    #[test]
+    fn validate_reports_unknown_keys_and_wrong_shapes() {
+        let cfg = config(
+            r#"
+            [temporal]
+            hots = "typo"
+            port = 7233
+            [remotes]
+            hosts = "git.example.com"
+            [llm.openai]
+            api_key = ""
+            "#,
+        );
+        let issues = cfg.validate();
+        let keys: Vec<&str> = issues.iter().map(|i| i.key.as_str()).collect();
+        assert_eq!(
+            keys,
+            vec![
+                "llm.openai.api_key",
+                "remotes.hosts",
+                "temporal.hots",
+                "temporal.port"
+            ]
+        );
+        assert!(issues[1].reason.contains("array of strings"));
+    }
+
+    #[test]
+    fn wildcard_matches_exactly_one_segment() {
+        assert!(key_matches(
+            "capability.*.services",
+            "capability.gws.services"
+        ));
+        assert!(!key_matches("capability.*.services", "capability.services"));
+        assert!(!key_matches(
+            "capability.*.services",
+            "capability.a.b.services"
+        ));
+    }
+
+    #[test]
+    fn interpolates_env_references_on_read() {
+        let cfg = config_with_env(
+            r#"
+            [temporal]
+            host = "http://${TEMPORAL_HOST}:${TEMPORAL_PORT:-7233}"
+            [llm.openai]
+            api_key = "${OPENAI_KEY}"
+            [remotes]
+            hosts = ["${GIT_HOST:-git.example.com}", "cost-$5", "lit-$${x}"]
+            "#,
+            &[("TEMPORAL_HOST", "10.0.0.5"), ("OPENAI_KEY", "sk-test")],
+        );
+        assert_eq!(
+            cfg.get("temporal.host").and_then(Value::as_str),
+            Some("http://10.0.0.5:7233")
+        );
+        assert_eq!(
+            cfg.get("llm.openai.api_key").and_then(Value::as_str),
+            Some("sk-test")
+        );
+        let hosts: Vec<&str> = cfg
+            .get("remotes.hosts")
+            .and_then(Value::as_array)
+            .unwrap()
+            .iter()
+            .filter_map(Value::as_str)
+            .collect();
+        assert_eq!(hosts, vec!["git.example.com", "cost-$5", "lit-${x}"]);
+        // The raw document keeps the references for `save`.
+        assert!(toml::to_string(&cfg.doc).unwrap().contains("${OPENAI_KEY}"));
+        assert!(cfg.validate().is_empty());
+    }
+
+    #[test]
+    fn unresolved_references_read_as_unset_and_fail_validation() {
+        let cfg = config_with_env(
+            r#"
+            [temporal]
+            host = "${MISSING_HOST}"
+            [llm.gemini]
+            api_key = "${BROKEN"
+            [llm.openai]
+            api_key = "${EMPTY:-}"
+            "#,
+            &[],
+        );
+        assert!(cfg.get("temporal.host").is_none());
+        assert!(cfg.get("llm.gemini.api_key").is_none());
+        let issues = cfg.validate();
+        let keys: Vec<&str> = issues.iter().map(|i| i.key.as_str()).collect();
+        assert_eq!(
+            keys,
+            vec!["llm.gemini.api_key", "llm.openai.api_key", "temporal.host"]
+        );
+        assert!(issues[0].reason.contains("unterminated"));
+        assert!(!issues[0].reason.contains("BROKEN"));
+        assert!(issues[1].reason.contains("must not be empty"));
+        assert!(issues[2].reason.contains("`MISSING_HOST` is not set"));
+    }
+
+    #[test]
+    fn set_literal_is_never_expanded() {
+        let mut cfg = config("");
+        cfg.set_literal("launcher.last_local_dir", "/tmp/${HOME}/a$${b")
+            .unwrap();
+        assert_eq!(
+            cfg.get("launcher.last_local_dir").and_then(Value::as_str),
+            Some("/tmp/${HOME}/a$${b")
+        );
+        cfg.set_literal("llm.openai.api_key", "12345").unwrap();
+        assert_eq!(
+            cfg.get("llm.openai.api_key").and_then(Value::as_str),
+            Some("12345")
+        );
+        assert!(cfg.validate().is_empty());
+    }
+
+    #[test]
+    fn parse_errors_do_not_quote_the_source() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "[llm.openai]\napi_key = sk-live-secret\n").unwrap();
+        let err = format!("{:#}", Config::load_from(&path).err().unwrap());
+        assert!(err.contains("line 2, column 11"), "{err}");
+        assert!(!err.contains("sk-live-secret"), "{err}");
+    }
+}
@@ -181,7 +181,9 @@ fn spawn_dev_temporal() -> Result<()> {
 
     let flag = announce_flag()?;
     if !flag.exists() {
-        println!("Started local execution engine.");
+        // stderr: callers like `check --json` / `status --json` / `cori
+        // mcp` own stdout, and a stray line there breaks their output.
+        eprintln!("Started local execution engine.");
         let _ = std::fs::write(&flag, "");
     }
     Ok(())
@@ -11,6 +11,13 @@ use sha2::{Digest, Sha256};
 use crate::paths;
 use crate::remote::{RemoteRef, Resolved};
 
+/// Version of the compiled-DAG format, mixed into [`cache_key`]. Bump it
+/// whenever the compiler starts emitting something new (e.g. step
+/// metadata the worker reads), so cached DAGs of unchanged folders —
+/// pinned remote refs never change — are recompiled instead of silently
+/// missing it. v2: step `timeout_ms` and `retries.backoff_ms`.
+const COMPILED_FORMAT_VERSION: u32 = 2;
+
 /// Outcome of [`load`]: the compiled DAG and a few derived strings the
 /// run pipeline reuses.
 pub struct LoadedWorkflow {
@@ -121,6 +128,7 @@ pub fn run_history_key(absolute_path: &Path, folder_name: &str) -> String {
 
 fn cache_key(absolute_path: &Path, content_hash: &str) -> String {
     let mut h = Sha256::new();
+    h.update(format!("v{COMPILED_FORMAT_VERSION}\0").as_bytes());
     h.update(absolute_path.as_os_str().to_string_lossy().as_bytes());
     h.update(content_hash.as_bytes());
     let digest = h.finalize();
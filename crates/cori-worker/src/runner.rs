@@ -15,6 +15,7 @@
 //! CLI pattern handles this by giving each `cori run` its own runtime.
 
 use std::future::Future;
+use std::time::Duration;
 
 use anyhow::Result;
 use temporalio_client::{WorkflowCancelOptions, WorkflowGetResultOptions, WorkflowStartOptions};
@@ -75,7 +76,7 @@ pub async fn run_workflow_once(
                 // After cancel request, give the workflow up to 5s to
                 // complete before forcing shutdown.
                 match tokio::time::timeout(
-                    std::time::Duration::from_secs(5),
+                    Duration::from_secs(5),
                     handle.get_result(WorkflowGetResultOptions::default()),
                 )
                 .await
@@ -104,13 +105,26 @@ pub async fn run_workflow_once(
     result
 }
 
-/// Run a long-lived worker on `rt.task_queue` until SIGINT.
+/// Default time `cori work` waits for in-flight activities after a
+/// shutdown signal before exiting anyway.
+pub const DEFAULT_DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Run a long-lived worker on `rt.task_queue` until SIGINT or SIGTERM.
 ///
 /// Used by `cori work`: registers the single workflow type + the four
-/// activity handlers and polls forever. Returns `Ok(())` after a clean
-/// shutdown triggered by Ctrl-C, or an error if worker construction
-/// fails.
-pub async fn serve_worker_until_signal(rt: &CoriTemporalRuntime) -> Result<()> {
+/// activity handlers and polls forever. On the first signal `on_signal`
+/// runs (the caller's hook to stop taking new work elsewhere — cron
+/// fires, its capability report), then the worker stops polling for new
+/// tasks and lets in-flight activities finish, for at most
+/// `drain_timeout`. Past that, or on a second signal, we return anyway
+/// and Temporal reschedules the abandoned attempts per their retry
+/// policy. Returns `Ok(())` after shutdown, or an error if worker
+/// construction fails.
+pub async fn serve_worker_until_signal(
+    rt: &CoriTemporalRuntime,
+    drain_timeout: Duration,
+    on_signal: impl FnOnce(),
+) -> Result<()> {
     let worker_options = WorkerOptions::new(rt.task_queue.clone())
         .register_workflow::<CoriWorkflow>()
         .register_activities(CoriActivities)
@@ -120,23 +134,60 @@ pub async fn serve_worker_until_signal(rt: &CoriTemporalRuntime) -> Result<()> {
     let shutdown_handle = worker.shutdown_handle();
     info!(task_queue = %rt.task_queue, "cori worker polling");
 
-    let signal_listener = async {
-        if tokio::signal::ctrl_c().await.is_ok() {
-            warn!("received SIGINT — shutting down worker");
-            shutdown_handle();
-        }
-    };
-
     let worker_fut = async {
         if let Err(e) = worker.run().await {
             warn!(error = %e, "temporal worker exited with error");
         }
     };
-
-    let (_, _) = tokio::join!(signal_listener, worker_fut);
+    tokio::pin!(worker_fut);
+
+    tokio::select! {
+        _ = &mut worker_fut => return Ok(()),
+        _ = shutdown_signal() => {}
+    }
+
+    warn!(
+        drain_timeout_secs = drain_timeout.as_secs(),
+        "shutdown signal received — draining in-flight activities (signal again to skip)"
+    );
+    on_signal();
+    shutdown_handle();
+    tokio::select! {
+        _ = &mut worker_fut => {}
+        _ = tokio::time::sleep(drain_timeout) => {
+            warn!("drain timeout elapsed with activities still in flight — exiting anyway");
+        }
+        _ = shutdown_signal() => {
+            warn!("second shutdown signal — exiting without waiting for in-flight activities");
+        }
+    }
     Ok(())
 }
 
+/// Resolves on the first SIGINT or (Unix) SIGTERM. If no handler can be
+/// installed, never resolves — same as the worker polling forever.
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{SignalKind, signal};
+        match signal(SignalKind::terminate()) {
+            Ok(mut term) => {
+                tokio::select! {
+                    r = tokio::signal::ctrl_c() => if r.is_ok() { return },
+                    _ = term.recv() => return,
+                }
+                // No SIGINT handler: SIGTERM alone still drains.
+                term.recv().await;
+                return;
+            }
+            Err(e) => warn!(error = %e, "could not install SIGTERM handler"),
+        }
+    }
+    if tokio::signal::ctrl_c().await.is_err() {
+        std::future::pending::<()>().await;
+    }
+}
+
 /// Like [`serve_worker_until_signal`] but driven by an injected
 /// cancellation future instead of `ctrl_c`. Used by the desktop app's
 /// tray "Quit" handler, where the cancellation source is a oneshot
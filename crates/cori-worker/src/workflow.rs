@@ -431,6 +431,8 @@ fn needs_reauth_details(err: &ActivityExecutionError) -> Option<NeedsReauthDetai
 /// - `retries.max` (number): overrides the default attempt cap.
 /// - `retries.backoff` (`"exponential"` | `"linear"`): retry backoff
 ///   strategy. Defaults to exponential.
+/// - `retries.backoff_ms` (number): delay before the first retry.
+///   Defaults to 1s.
 fn activity_options_for_step(step: &cori_protocol::CompiledStep) -> ActivityOptions {
     let default_secs: u64 = match step.kind {
         StepKind::Cli => 60,
@@ -471,11 +473,18 @@ fn activity_options_for_step(step: &cori_protocol::CompiledStep) -> ActivityOpti
         Some("linear") => 1.0,
         _ => 2.0,
     };
+    let initial_interval = retries
+        .and_then(|r| r.get("backoff_ms"))
+        .and_then(|v| v.as_u64())
+        .map(Duration::from_millis)
+        .unwrap_or(Duration::from_secs(1));
+    // Temporal rejects a policy whose cap is below its first interval.
+    let maximum_interval = initial_interval.max(Duration::from_secs(60));
 
     let retry_policy = temporalio_common::protos::temporal::api::common::v1::RetryPolicy {
-        initial_interval: Some(prost_duration_from_secs(1)),
+        initial_interval: Some(prost_duration(initial_interval)),
         backoff_coefficient,
-        maximum_interval: Some(prost_duration_from_secs(60)),
+        maximum_interval: Some(prost_duration(maximum_interval)),
         maximum_attempts: max_attempts,
         non_retryable_error_types: vec![
             "MissingCapabilityError".to_string(),
@@ -501,9 +510,9 @@ fn activity_options_for_step(step: &cori_protocol::CompiledStep) -> ActivityOpti
         .build()
 }
 
-fn prost_duration_from_secs(s: i64) -> prost_wkt_types::Duration {
+fn prost_duration(d: Duration) -> prost_wkt_types::Duration {
     prost_wkt_types::Duration {
-        seconds: s,
-        nanos: 0,
+        seconds: d.as_secs() as i64,
+        nanos: d.subsec_nanos() as i32,
     }
 }
@@ -24,6 +24,8 @@ export type BackoffKind = "exponential" | "linear";
 export interface RetryPolicy {
   readonly max: number;
   readonly backoff: BackoffKind;
+  /** Delay before the first retry in milliseconds (default 1000). */
+  readonly backoff_ms?: number;
 }
 
 export interface BaseStepOpts {